@@ -0,0 +1,61 @@
+mod notify;
+
+pub use notify::{BuildStatusEvent, BuildStatusNotifier};
+
+/// Thin wrapper around the Postgres connection pool plus the
+/// `build_status` LISTEN/NOTIFY subscription, cloned into every route's
+/// `AppState`.
+#[derive(Clone)]
+pub struct DbClient {
+    pool: sqlx::PgPool,
+    notifier: BuildStatusNotifier,
+}
+
+impl DbClient {
+    pub async fn new(database_url: &str) -> Result<Self, sqlx::Error> {
+        let pool = sqlx::PgPool::connect(database_url).await?;
+        let notifier = BuildStatusNotifier::connect(database_url).await?;
+        Ok(Self { pool, notifier })
+    }
+
+    /// Subscribes to build lifecycle events pushed over `pg_notify`. Status
+    /// updates made by this process (or any other replica) surface here
+    /// without needing to poll the `builds` table. `lifecycle::spawn_reactor`
+    /// is the sole in-process subscriber today.
+    pub fn subscribe_build_status(&self) -> tokio::sync::broadcast::Receiver<BuildStatusEvent> {
+        self.notifier.subscribe()
+    }
+
+    /// Looks up a build by its request id, used by the lifecycle reactor to
+    /// resolve a `pg_notify` payload back to the exact build the log
+    /// channel and `notify` target are keyed on.
+    pub async fn get_build_by_id(
+        &self,
+        id: &str,
+    ) -> Result<crate::models::SolanaProgramBuild, sqlx::Error> {
+        sqlx::query_as!(
+            crate::models::SolanaProgramBuild,
+            "SELECT * FROM builds WHERE id = $1",
+            id
+        )
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    /// Atomically claims the right to send `build_id`'s completion
+    /// notification. Every replica's reactor sees the same `pg_notify`, so
+    /// without this an N-replica deployment would send N duplicate
+    /// emails/webhook POSTs per build. Returns `true` only for whichever
+    /// caller's `UPDATE` wins the race; everyone else gets `false` and
+    /// should skip dispatch.
+    pub async fn claim_completion_notification(&self, build_id: &str) -> Result<bool, sqlx::Error> {
+        let claimed = sqlx::query!(
+            "UPDATE builds SET notified_at = now() WHERE id = $1 AND notified_at IS NULL",
+            build_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(claimed.rows_affected() == 1)
+    }
+}
@@ -0,0 +1,85 @@
+use sqlx::postgres::PgListener;
+use tokio::sync::broadcast;
+
+use crate::models::JobStatus;
+
+/// Channel name the `build_status` trigger notifies on. The trigger
+/// function in the migrations spells this name out itself, so the two must
+/// be kept in step by hand.
+pub const BUILD_STATUS_CHANNEL: &str = "build_status";
+
+/// A build lifecycle event decoded from a `pg_notify('build_status', ...)`
+/// payload, of the form `"<id>:<program_id>:<status>"`. The build `id` (the
+/// request id, not the program id) is what the reactor must key on: a
+/// program can have many builds over time, so resolving by `program_id`
+/// alone risks matching a newer build than the one this event is about.
+#[derive(Debug, Clone)]
+pub struct BuildStatusEvent {
+    pub id: String,
+    pub program_id: String,
+    pub status: JobStatus,
+}
+
+impl BuildStatusEvent {
+    fn parse(payload: &str) -> Option<Self> {
+        let mut parts = payload.splitn(3, ':');
+        let id = parts.next()?.to_string();
+        let program_id = parts.next()?.to_string();
+        let status = parts.next()?.parse().ok()?;
+        Some(Self {
+            id,
+            program_id,
+            status,
+        })
+    }
+}
+
+/// Listens on the Postgres `build_status` channel and fans the decoded
+/// events out to any number of in-process subscribers. Today the only one
+/// is `lifecycle::spawn_reactor`, which drives the SSE terminal event and
+/// the completion notifier.
+#[derive(Clone)]
+pub struct BuildStatusNotifier {
+    sender: broadcast::Sender<BuildStatusEvent>,
+}
+
+impl BuildStatusNotifier {
+    /// Connects a `PgListener` on `BUILD_STATUS_CHANNEL` and spawns a task
+    /// that forwards every notification to subscribers for the lifetime of
+    /// the process.
+    pub async fn connect(database_url: &str) -> Result<Self, sqlx::Error> {
+        let mut listener = PgListener::connect(database_url).await?;
+        listener.listen(BUILD_STATUS_CHANNEL).await?;
+
+        let (sender, _) = broadcast::channel(1024);
+        let task_sender = sender.clone();
+
+        tokio::spawn(async move {
+            loop {
+                match listener.recv().await {
+                    Ok(notification) => {
+                        if let Some(event) = BuildStatusEvent::parse(notification.payload()) {
+                            let _ = task_sender.send(event);
+                        } else {
+                            tracing::warn!(
+                                "Received malformed build_status notification: {}",
+                                notification.payload()
+                            );
+                        }
+                    }
+                    Err(err) => {
+                        tracing::error!("build_status listener error: {:?}", err);
+                    }
+                }
+            }
+        });
+
+        Ok(Self { sender })
+    }
+
+    /// Subscribes to build lifecycle events, e.g. to push an SSE terminal
+    /// event or trigger a webhook notification on completion.
+    pub fn subscribe(&self) -> broadcast::Receiver<BuildStatusEvent> {
+        self.sender.subscribe()
+    }
+}
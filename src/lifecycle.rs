@@ -0,0 +1,119 @@
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::db::{BuildStatusEvent, DbClient};
+use crate::logs::LogBroadcaster;
+use crate::models::{JobStatus, StatusResponse};
+use crate::notifier::{self, NotifyTarget};
+use crate::queue::Notifiers;
+
+/// Spawns the task that reacts to build lifecycle events sourced from
+/// Postgres `LISTEN/NOTIFY` (`DbClient::subscribe_build_status`) rather
+/// than having the worker that wrote the status call these side effects
+/// directly. This is what lets the SSE terminal event and the completion
+/// notifier fire for a build that a *different* replica's worker picked
+/// off the queue and finished.
+pub fn spawn_reactor(db: DbClient, logs: LogBroadcaster, notifiers: Notifiers) {
+    let mut events = db.subscribe_build_status();
+
+    tokio::spawn(async move {
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "build status reactor missed notifications, continuing");
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+
+            if !matches!(event.status, JobStatus::Completed | JobStatus::Failed) {
+                continue;
+            }
+
+            react_to_terminal_status(&db, &logs, &notifiers, event).await;
+        }
+    });
+}
+
+async fn react_to_terminal_status(
+    db: &DbClient,
+    logs: &LogBroadcaster,
+    notifiers: &Notifiers,
+    event: BuildStatusEvent,
+) {
+    let build = match db.get_build_by_id(&event.id).await {
+        Ok(build) => build,
+        Err(err) => {
+            tracing::error!(
+                id = %event.id,
+                "terminal status notification for a build we can't look up: {:?}",
+                err
+            );
+            return;
+        }
+    };
+
+    // Every replica's reactor sees this event, so the SSE terminal push is
+    // safe to run everywhere - it's a no-op wherever this process doesn't
+    // hold that build's (process-local) log channel. The completion
+    // notifier is not idempotent that way, so it's gated below on winning
+    // an atomic claim.
+    logs.push_terminal(&build.id, status_label(event.status));
+
+    let Some(target) = build.notify.as_deref().and_then(NotifyTarget::parse) else {
+        return;
+    };
+
+    match db.claim_completion_notification(&build.id).await {
+        Ok(true) => {}
+        Ok(false) => return,
+        Err(err) => {
+            tracing::error!("Failed to claim completion notification: {:?}", err);
+            return;
+        }
+    }
+
+    let repo_url = build
+        .commit_hash
+        .clone()
+        .map_or(build.repository.clone(), |hash| {
+            format!("{}/commit/{}", build.repository, hash)
+        });
+
+    let response = match event.status {
+        JobStatus::Completed => match db.get_verified_build(&event.program_id).await {
+            Ok(verified) => StatusResponse {
+                is_verified: verified.is_verified,
+                message: if verified.is_verified {
+                    "On chain program verified".to_string()
+                } else {
+                    "On chain program not verified".to_string()
+                },
+                on_chain_hash: verified.on_chain_hash,
+                executable_hash: verified.executable_hash,
+                repo_url,
+            },
+            Err(err) => {
+                tracing::error!("Failed to load verified build for notification: {:?}", err);
+                return;
+            }
+        },
+        _ => StatusResponse {
+            is_verified: false,
+            message: "On chain program verification failed".to_string(),
+            on_chain_hash: None,
+            executable_hash: None,
+            repo_url,
+        },
+    };
+
+    notifier::dispatch(&target, &response, &notifiers.email, &notifiers.webhook).await;
+}
+
+fn status_label(status: JobStatus) -> &'static str {
+    match status {
+        JobStatus::Completed => "completed",
+        JobStatus::Failed => "failed",
+        JobStatus::InProgress => "in_progress",
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+
+/// Number of buffered log lines a late-subscribing client can replay before
+/// it starts tailing live output.
+const LOG_BUFFER_CAPACITY: usize = 1024;
+
+/// How long a finished build's channel is kept around after its terminal
+/// event so a client subscribing right as the build completes still sees
+/// it, instead of racing `close` and getting "no build found".
+const FINISHED_RETENTION: Duration = Duration::from_secs(5 * 60);
+
+/// One item in a build's log stream: either a line of build output, or the
+/// terminal status ("completed"/"failed") the SSE handler should surface
+/// as a distinct `event: done` before the stream ends.
+#[derive(Clone)]
+pub enum LogEntry {
+    Line(String),
+    Terminal(String),
+}
+
+struct LogChannel {
+    sender: broadcast::Sender<LogEntry>,
+    buffered: Vec<LogEntry>,
+}
+
+/// In-memory fan-out of build log lines, keyed by request UUID.
+///
+/// `verify_async` creates a channel when it hands the job to the build
+/// queue, `verify_build` pushes lines into it as the docker/cargo build
+/// progresses, and the `/verify/:request_id/logs` SSE handler replays the
+/// buffer and then tails new lines until `push_terminal` emits the job's
+/// final status.
+#[derive(Clone, Default)]
+pub struct LogBroadcaster {
+    channels: Arc<Mutex<HashMap<String, LogChannel>>>,
+}
+
+impl LogBroadcaster {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a new channel for `request_id`. Safe to call more than
+    /// once; re-registering clears any previously buffered lines.
+    pub fn register(&self, request_id: &str) {
+        let (sender, _) = broadcast::channel(LOG_BUFFER_CAPACITY);
+        self.channels.lock().unwrap().insert(
+            request_id.to_string(),
+            LogChannel {
+                sender,
+                buffered: Vec::new(),
+            },
+        );
+    }
+
+    /// Appends a log line and broadcasts it to any live subscribers.
+    pub fn push_line(&self, request_id: &str, line: impl Into<String>) {
+        self.push(request_id, LogEntry::Line(line.into()));
+    }
+
+    /// Emits the job's terminal status (e.g. "completed"/"failed") as the
+    /// last entry in the stream, then schedules the channel for removal
+    /// after `FINISHED_RETENTION` so a client subscribing at the same
+    /// moment the build finishes still gets the buffered terminal event
+    /// instead of a "no build found" response.
+    pub fn push_terminal(&self, request_id: &str, status: impl Into<String>) {
+        self.push(request_id, LogEntry::Terminal(status.into()));
+
+        let channels = self.channels.clone();
+        let request_id = request_id.to_string();
+        tokio::spawn(async move {
+            tokio::time::sleep(FINISHED_RETENTION).await;
+            channels.lock().unwrap().remove(&request_id);
+        });
+    }
+
+    fn push(&self, request_id: &str, entry: LogEntry) {
+        if let Some(channel) = self.channels.lock().unwrap().get_mut(request_id) {
+            if channel.buffered.len() == LOG_BUFFER_CAPACITY {
+                channel.buffered.remove(0);
+            }
+            channel.buffered.push(entry.clone());
+            let _ = channel.sender.send(entry);
+        }
+    }
+
+    /// Returns the buffered entries so far plus a receiver for everything
+    /// that arrives after. `None` if no build is registered under this id.
+    pub fn subscribe(&self, request_id: &str) -> Option<(Vec<LogEntry>, broadcast::Receiver<LogEntry>)> {
+        let channels = self.channels.lock().unwrap();
+        let channel = channels.get(request_id)?;
+        Some((channel.buffered.clone(), channel.sender.subscribe()))
+    }
+}
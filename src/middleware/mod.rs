@@ -0,0 +1,3 @@
+mod rate_limit;
+
+pub(crate) use rate_limit::{rate_limit, RateLimiter};
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::extract::{ConnectInfo, State};
+use axum::http::{HeaderValue, Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+
+use crate::models::{ApiResponse, ErrorResponse, Status};
+
+/// How often the eviction sweep runs, and how long a bucket can sit idle
+/// before it's dropped. Without this, one entry per distinct client IP
+/// accumulates forever - an unbounded-memory DoS vector on its own.
+const EVICTION_INTERVAL: Duration = Duration::from_secs(60);
+const IDLE_RETENTION: Duration = Duration::from_secs(600);
+
+/// Per-client token bucket: `tokens` refills to `capacity` at a constant
+/// rate and is drained by one on every accepted request.
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// In-memory, per-IP token-bucket rate limiter wrapped around a single
+/// route. Kept separate from auth/logging middleware so it can sit only
+/// in front of `/verify` - cheap reads like `/status` and `/verify/:id/logs`
+/// stay unthrottled.
+///
+/// Keys on the TCP peer address (`ConnectInfo`), which is correct when the
+/// API is reachable directly. Behind a reverse proxy or load balancer every
+/// client collapses to the proxy's IP; deploying behind one requires
+/// reading the real client from `X-Forwarded-For` (and trusting it only
+/// because the proxy is configured to overwrite rather than append to that
+/// header) instead of `ConnectInfo`, which this middleware does not yet do.
+#[derive(Clone)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<HashMap<IpAddr, Bucket>>>,
+    capacity: f64,
+    refill_per_second: f64,
+}
+
+impl RateLimiter {
+    /// `capacity` is the burst size and the per-window limit; `window`
+    /// is how long it takes a fully drained bucket to refill. Spawns a
+    /// background sweep that evicts buckets idle for longer than
+    /// `IDLE_RETENTION` so a one-off client doesn't hold memory forever.
+    pub fn new(capacity: u32, window: Duration) -> Self {
+        let limiter = Self {
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+            capacity: capacity as f64,
+            refill_per_second: capacity as f64 / window.as_secs_f64(),
+        };
+
+        limiter.spawn_eviction_sweep();
+        limiter
+    }
+
+    fn spawn_eviction_sweep(&self) {
+        let buckets = self.buckets.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(EVICTION_INTERVAL).await;
+                let now = Instant::now();
+                buckets
+                    .lock()
+                    .unwrap()
+                    .retain(|_, bucket| now.duration_since(bucket.last_refill) < IDLE_RETENTION);
+            }
+        });
+    }
+
+    /// Returns `Ok(())` if the request may proceed, or the number of
+    /// seconds the caller should wait before retrying.
+    fn check(&self, client: IpAddr) -> Result<(), u64> {
+        let mut buckets = self.buckets.lock().unwrap();
+        let now = Instant::now();
+        let bucket = buckets.entry(client).or_insert(Bucket {
+            tokens: self.capacity,
+            last_refill: now,
+        });
+
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let seconds_to_next_token = ((1.0 - bucket.tokens) / self.refill_per_second).ceil();
+            Err(seconds_to_next_token.max(1.0) as u64)
+        }
+    }
+}
+
+/// Axum middleware that rejects requests over the configured rate with
+/// `429 Too Many Requests` and a `Retry-After` header. Intended to wrap
+/// only the `/verify` route, in front of the build queue, not the whole
+/// router.
+pub(crate) async fn rate_limit(
+    State(limiter): State<RateLimiter>,
+    ConnectInfo(addr): ConnectInfo<std::net::SocketAddr>,
+    request: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    match limiter.check(addr.ip()) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            let mut response = (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(ApiResponse::from(ErrorResponse {
+                    status: Status::Error,
+                    error: "Rate limit exceeded, please slow down and retry later".to_string(),
+                })),
+            )
+                .into_response();
+
+            if let Ok(value) = HeaderValue::from_str(&retry_after.to_string()) {
+                response.headers_mut().insert("Retry-After", value);
+            }
+
+            response
+        }
+    }
+}
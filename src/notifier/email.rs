@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use crate::models::StatusResponse;
+
+use super::{CompletionNotifier, NotifierError};
+
+/// Delivers completion notifications by emailing a plain-text summary of
+/// the finished build's `StatusResponse` over SMTP.
+#[derive(Clone)]
+pub struct EmailNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: String,
+}
+
+impl EmailNotifier {
+    pub fn new(transport: AsyncSmtpTransport<Tokio1Executor>, from: String) -> Self {
+        Self { transport, from }
+    }
+}
+
+#[async_trait]
+impl CompletionNotifier for EmailNotifier {
+    async fn notify(&self, target: &str, response: &StatusResponse) -> Result<(), NotifierError> {
+        let subject = if response.is_verified {
+            "Program verification completed: verified"
+        } else {
+            "Program verification completed: not verified"
+        };
+
+        let body = format!(
+            "Verification result: {}\non_chain_hash: {}\nexecutable_hash: {}\nrepository: {}\n",
+            response.message,
+            response.on_chain_hash.as_deref().unwrap_or("n/a"),
+            response.executable_hash.as_deref().unwrap_or("n/a"),
+            response.repo_url,
+        );
+
+        let email = Message::builder()
+            .from(self.from.parse().map_err(|err| {
+                NotifierError::Delivery(format!("invalid from address: {err}"))
+            })?)
+            .to(target
+                .parse()
+                .map_err(|err| NotifierError::Delivery(format!("invalid to address: {err}")))?)
+            .subject(subject)
+            .body(body)
+            .map_err(|err| NotifierError::Delivery(err.to_string()))?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|err| NotifierError::Delivery(err.to_string()))?;
+        Ok(())
+    }
+}
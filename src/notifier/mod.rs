@@ -0,0 +1,68 @@
+mod email;
+mod webhook;
+
+pub use email::EmailNotifier;
+pub use webhook::WebhookNotifier;
+
+use async_trait::async_trait;
+
+use crate::models::StatusResponse;
+
+/// Where a verification result should be pushed once a job reaches a
+/// terminal status. Parsed from the optional `notify` field on
+/// `SolanaProgramBuildParams`.
+#[derive(Debug, Clone)]
+pub enum NotifyTarget {
+    Email(String),
+    Webhook(String),
+}
+
+impl NotifyTarget {
+    /// An email address is distinguished from a callback URL by the
+    /// presence of an `@` with no scheme, matching how `/verify` already
+    /// accepts a single freeform `notify` string.
+    pub fn parse(notify: &str) -> Option<Self> {
+        if notify.starts_with("http://") || notify.starts_with("https://") {
+            Some(Self::Webhook(notify.to_string()))
+        } else if notify.contains('@') {
+            Some(Self::Email(notify.to_string()))
+        } else {
+            None
+        }
+    }
+}
+
+/// A transport capable of delivering a finished build's `StatusResponse`
+/// to whoever asked to be notified. Kept as a trait so new transports
+/// (Slack, SMS, ...) can be added without touching the dispatch site.
+#[async_trait]
+pub trait CompletionNotifier: Send + Sync {
+    async fn notify(&self, target: &str, response: &StatusResponse) -> Result<(), NotifierError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum NotifierError {
+    #[error("failed to send notification: {0}")]
+    Delivery(String),
+}
+
+/// Dispatches a finished build's `StatusResponse` to the target encoded in
+/// the job's `notify` field, picking the transport based on `NotifyTarget`.
+/// Failures are logged but never surfaced to the caller of `/verify` -
+/// the build itself already completed successfully or failed on its own
+/// terms, independent of whether the notification goes through.
+pub async fn dispatch(
+    target: &NotifyTarget,
+    response: &StatusResponse,
+    email: &EmailNotifier,
+    webhook: &WebhookNotifier,
+) {
+    let result = match target {
+        NotifyTarget::Email(address) => email.notify(address, response).await,
+        NotifyTarget::Webhook(url) => webhook.notify(url, response).await,
+    };
+
+    if let Err(err) = result {
+        tracing::error!("Failed to deliver completion notification: {:?}", err);
+    }
+}
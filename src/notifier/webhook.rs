@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::models::StatusResponse;
+
+use super::{CompletionNotifier, NotifierError};
+
+/// Delivers completion notifications by POSTing the `StatusResponse` JSON
+/// to a user-supplied callback URL.
+#[derive(Clone, Default)]
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+}
+
+impl WebhookNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CompletionNotifier for WebhookNotifier {
+    async fn notify(&self, target: &str, response: &StatusResponse) -> Result<(), NotifierError> {
+        self.client
+            .post(target)
+            .json(response)
+            .send()
+            .await
+            .map_err(|err| NotifierError::Delivery(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| NotifierError::Delivery(err.to_string()))?;
+        Ok(())
+    }
+}
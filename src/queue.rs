@@ -0,0 +1,189 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::builder::verify_build;
+use crate::db::DbClient;
+use crate::lifecycle;
+use crate::logs::LogBroadcaster;
+use crate::models::{JobStatus, SolanaProgramBuild, SolanaProgramBuildParams};
+use crate::notifier::{EmailNotifier, WebhookNotifier};
+
+/// A build accepted by `/verify` but not yet picked up by a worker.
+struct QueuedJob {
+    params: SolanaProgramBuildParams,
+    build: SolanaProgramBuild,
+}
+
+/// Counts surfaced by `GET /queue` so operators can see backpressure before
+/// it turns into timeouts.
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct QueueStats {
+    pub queued: usize,
+    pub running: usize,
+    pub capacity: usize,
+}
+
+/// The notification transports available to a finished job. Cheap to
+/// clone; both wrap a pooled client.
+#[derive(Clone)]
+pub struct Notifiers {
+    pub email: EmailNotifier,
+    pub webhook: WebhookNotifier,
+}
+
+impl Notifiers {
+    pub fn new(email: EmailNotifier, webhook: WebhookNotifier) -> Self {
+        Self { email, webhook }
+    }
+}
+
+/// Bounded queue of verification jobs drained by a fixed pool of worker
+/// tasks, replacing the previous unbounded `tokio::spawn` per request. A
+/// burst of `/verify` calls now backs up in the channel instead of
+/// spawning unbounded concurrent docker builds, and `capacity` doubles as
+/// the number of builds that can run at once.
+#[derive(Clone)]
+pub struct BuildQueue {
+    sender: mpsc::Sender<QueuedJob>,
+    queued: Arc<AtomicUsize>,
+    running: Arc<AtomicUsize>,
+    capacity: usize,
+}
+
+impl BuildQueue {
+    /// Spawns `capacity` worker tasks pulling from a channel of the same
+    /// bound, starts the lifecycle reactor that turns terminal status
+    /// writes into SSE/notifier side effects, and requeues any row still
+    /// marked `InProgress` from before a restart so crashed builds aren't
+    /// silently dropped.
+    pub async fn start(
+        db: DbClient,
+        logs: LogBroadcaster,
+        notifiers: Notifiers,
+        capacity: usize,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel(capacity);
+        let queue = Self {
+            sender,
+            queued: Arc::new(AtomicUsize::new(0)),
+            running: Arc::new(AtomicUsize::new(0)),
+            capacity,
+        };
+
+        queue.spawn_workers(receiver, db.clone(), logs.clone(), capacity);
+        lifecycle::spawn_reactor(db.clone(), logs.clone(), notifiers);
+        queue.requeue_in_progress(db, logs).await;
+
+        queue
+    }
+
+    /// Spawns exactly `capacity` worker tasks pulling from a channel of the
+    /// same bound. That worker count is already the concurrency limit - a
+    /// guarding semaphore with exactly as many permits as workers can never
+    /// block, so it would be redundant machinery rather than an actual
+    /// limiter.
+    fn spawn_workers(
+        &self,
+        receiver: mpsc::Receiver<QueuedJob>,
+        db: DbClient,
+        logs: LogBroadcaster,
+        capacity: usize,
+    ) {
+        let receiver = Arc::new(tokio::sync::Mutex::new(receiver));
+        let queued = self.queued.clone();
+        let running = self.running.clone();
+
+        for worker_id in 0..capacity {
+            let receiver = receiver.clone();
+            let db = db.clone();
+            let logs = logs.clone();
+            let queued = queued.clone();
+            let running = running.clone();
+
+            tokio::spawn(async move {
+                loop {
+                    let job = {
+                        let mut receiver = receiver.lock().await;
+                        receiver.recv().await
+                    };
+                    let Some(job) = job else {
+                        break;
+                    };
+                    queued.fetch_sub(1, Ordering::SeqCst);
+                    running.fetch_add(1, Ordering::SeqCst);
+
+                    tracing::info!(worker_id, program_id = %job.build.program_id, "picked up queued build");
+                    run_job(job, &db, &logs).await;
+
+                    running.fetch_sub(1, Ordering::SeqCst);
+                }
+            });
+        }
+    }
+
+    /// On startup, resubmit any build still marked `InProgress`. A crash
+    /// mid-build otherwise strands the row forever in that state.
+    async fn requeue_in_progress(&self, db: DbClient, logs: LogBroadcaster) {
+        match db.get_in_progress_builds().await {
+            Ok(stranded) => {
+                for (params, build) in stranded {
+                    tracing::warn!(program_id = %build.program_id, "requeuing build stranded by a restart");
+                    logs.register(&build.id);
+                    self.enqueue(QueuedJob { params, build }).await;
+                }
+            }
+            Err(err) => {
+                tracing::error!("Failed to load in-progress builds on startup: {:?}", err);
+            }
+        }
+    }
+
+    /// Enqueues a newly accepted build. Blocks until there's room in the
+    /// bounded channel, which is the desired backpressure: `/verify` stays
+    /// a cheap insert, and the caller's request simply takes a little
+    /// longer to return under load rather than the server falling over.
+    pub async fn submit(&self, params: SolanaProgramBuildParams, build: SolanaProgramBuild) {
+        self.enqueue(QueuedJob { params, build }).await;
+    }
+
+    async fn enqueue(&self, job: QueuedJob) {
+        self.queued.fetch_add(1, Ordering::SeqCst);
+        if self.sender.send(job).await.is_err() {
+            tracing::error!("Build queue receiver dropped; worker pool is no longer running");
+            self.queued.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    pub fn stats(&self) -> QueueStats {
+        QueueStats {
+            queued: self.queued.load(Ordering::SeqCst),
+            running: self.running.load(Ordering::SeqCst),
+            capacity: self.capacity,
+        }
+    }
+}
+
+/// Runs the build and records its outcome. Deliberately does nothing else:
+/// the `build_status` trigger turns each status write below into a
+/// `pg_notify`, and `lifecycle::spawn_reactor` is what reacts to that with
+/// the SSE terminal event and the completion notifier - so the same thing
+/// happens here as it would for a build resumed by a different replica
+/// after `requeue_in_progress`.
+async fn run_job(job: QueuedJob, db: &DbClient, logs: &LogBroadcaster) {
+    match verify_build(job.params, &job.build.id, logs.clone()).await {
+        Ok(res) => {
+            let _ = db.insert_or_update_verified_build(&res).await;
+            let _ = db
+                .update_build_status(&job.build.id, JobStatus::Completed.into())
+                .await;
+        }
+        Err(err) => {
+            let _ = db
+                .update_build_status(&job.build.id, JobStatus::Failed.into())
+                .await;
+            tracing::error!("Error verifying build: {:?}", err);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::extract::{Path, State};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures::future;
+use futures::stream::{self, Stream, StreamExt};
+
+use crate::logs::LogEntry;
+use crate::state::AppState;
+
+fn to_event(entry: LogEntry) -> Event {
+    match entry {
+        LogEntry::Line(line) => Event::default().data(line),
+        LogEntry::Terminal(status) => Event::default().event("done").data(status),
+    }
+}
+
+/// Route handler for GET /verify/:request_id/logs which streams the build
+/// output for a verification job as Server-Sent Events. Replays whatever
+/// has already been buffered for the request, then tails new lines as they
+/// arrive until `push_terminal` emits a final `event: done` carrying the
+/// job's terminal status.
+pub(crate) async fn stream_build_logs(
+    State(state): State<AppState>,
+    Path(request_id): Path<String>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = match state.logs.subscribe(&request_id) {
+        Some((buffered, receiver)) => {
+            let tail = tokio_stream::wrappers::BroadcastStream::new(receiver)
+                .filter_map(|entry| async move { entry.ok() });
+            // End the stream right after the terminal entry, whether it was
+            // replayed from the buffer or arrived on the live tail.
+            stream::iter(buffered)
+                .chain(tail)
+                .scan(false, |done, entry| {
+                    if *done {
+                        return future::ready(None);
+                    }
+                    *done = matches!(entry, LogEntry::Terminal(_));
+                    future::ready(Some(Ok::<_, Infallible>(to_event(entry))))
+                })
+                .left_stream()
+        }
+        None => stream::once(async {
+            Ok(Event::default()
+                .event("error")
+                .data("no build found for this request_id"))
+        })
+        .right_stream(),
+    };
+
+    Sse::new(stream).keep_alive(KeepAlive::new().interval(Duration::from_secs(15)))
+}
@@ -0,0 +1,4 @@
+pub(crate) mod logs;
+pub(crate) mod queue;
+pub(crate) mod verify_async;
+pub(crate) mod webhook;
@@ -0,0 +1,11 @@
+use axum::extract::State;
+use axum::Json;
+
+use crate::queue::QueueStats;
+use crate::state::AppState;
+
+// Route handler for GET /queue which reports queued/running/capacity
+// counts so operators can see backpressure on the build worker pool.
+pub(crate) async fn queue_stats(State(state): State<AppState>) -> Json<QueueStats> {
+    Json(state.queue.stats())
+}
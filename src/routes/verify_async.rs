@@ -1,17 +1,19 @@
-use crate::builder::verify_build;
-use crate::db::DbClient;
 use crate::models::{
     ApiResponse, ErrorResponse, JobStatus, SolanaProgramBuild, SolanaProgramBuildParams, Status,
     StatusResponse, VerifyResponse,
 };
+use crate::state::AppState;
 use axum::{extract::State, http::StatusCode, Json};
 use chrono::Utc;
 
-// Route handler for POST /verify which creates a new process to verify the program
+// Route handler for POST /verify which accepts the job and hands it to the
+// bounded build queue rather than running the docker build inline.
 pub(crate) async fn verify_async(
-    State(db): State<DbClient>,
+    State(state): State<AppState>,
     Json(payload): Json<SolanaProgramBuildParams>,
 ) -> (StatusCode, Json<ApiResponse>) {
+    let db = state.db.clone();
+    let logs = state.logs.clone();
     let uuid = uuid::Uuid::new_v4().to_string();
     let verify_build_data = SolanaProgramBuild {
         id: uuid.clone(),
@@ -24,6 +26,7 @@ pub(crate) async fn verify_async(
         base_docker_image: payload.base_image.clone(),
         mount_path: payload.mount_path.clone(),
         cargo_args: payload.cargo_args.clone(),
+        notify: payload.notify.clone(),
         status: JobStatus::InProgress.into(),
     };
 
@@ -110,26 +113,20 @@ pub(crate) async fn verify_async(
 
     tracing::info!("Inserted into database");
 
-    //run task in background
-    tokio::spawn(async move {
-        match verify_build(payload, &verify_build_data.id).await {
-            Ok(res) => {
-                let _ = db.insert_or_update_verified_build(&res).await;
-                let _ = db
-                    .update_build_status(&verify_build_data.id, JobStatus::Completed.into())
-                    .await;
-            }
-            Err(err) => {
-                let _ = db
-                    .update_build_status(&verify_build_data.id, JobStatus::Failed.into())
-                    .await;
-                tracing::error!("Error verifying build: {:?}", err);
-                tracing::error!(
-                    "We encountered an unexpected error during the verification process."
-                );
-            }
-        }
-    });
+    // Register the log channel before handing the job to the queue so
+    // clients subscribing immediately after this response don't miss the
+    // first lines.
+    logs.register(&uuid);
+
+    // Hand off to the worker pool instead of spawning an unbounded task:
+    // this blocks only until there's room in the bounded channel, giving
+    // the queue's capacity as natural backpressure on docker builds. The
+    // worker only writes status transitions to `db`; the `build_status`
+    // trigger turns each write into a `pg_notify`, and
+    // `lifecycle::spawn_reactor` (subscribed via
+    // `DbClient::subscribe_build_status`) is what reacts to that with the
+    // SSE terminal event and the completion notifier.
+    state.queue.submit(payload, verify_build_data).await;
 
     (
         StatusCode::OK,
@@ -0,0 +1,219 @@
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+use crate::models::{ApiResponse, ErrorResponse, SolanaProgramBuildParams, Status, VerifyResponse};
+use crate::state::AppState;
+
+use super::verify_async::verify_async;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// A single repo wired up for auto re-verification. The repo is matched
+/// against the payload's `repository.html_url` so the right pre-shared key
+/// is used when multiple repos are wired to the same webhook endpoint, and
+/// `program_id`/`lib_name` are threaded into the resulting build params
+/// since a GitHub push/release payload has no notion of a Solana program.
+#[derive(Clone, Debug)]
+pub struct GithubWebhookConfig {
+    pub repo: String,
+    pub secret: String,
+    pub program_id: String,
+    pub lib_name: Option<String>,
+}
+
+/// The subset of the GitHub push/release payload we care about: where the
+/// code lives and which commit to verify against the on-chain program.
+#[derive(Deserialize)]
+struct GithubPushPayload {
+    repository: GithubRepository,
+    #[serde(default)]
+    after: Option<String>,
+    #[serde(default)]
+    release: Option<GithubRelease>,
+}
+
+#[derive(Deserialize)]
+struct GithubRepository {
+    html_url: String,
+}
+
+#[derive(Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+}
+
+#[derive(Deserialize)]
+struct GithubTag {
+    name: String,
+    commit: GithubTagCommit,
+}
+
+#[derive(Deserialize)]
+struct GithubTagCommit {
+    sha: String,
+}
+
+impl GithubPushPayload {
+    /// The push event's `after` is already the exact commit SHA; prefer it.
+    /// `release.target_commitish` is commonly a branch name (e.g. `main`),
+    /// not the SHA the release was tagged at, so for a release event the
+    /// tag has to be resolved to its commit via the GitHub API instead of
+    /// trusting that field - otherwise re-verification builds whatever the
+    /// branch currently points at rather than the tagged commit.
+    async fn commit_hash(&self) -> Option<String> {
+        if let Some(after) = &self.after {
+            return Some(after.clone());
+        }
+
+        let release = self.release.as_ref()?;
+        resolve_tag_commit(&self.repository.html_url, &release.tag_name).await
+    }
+}
+
+/// Resolves a tag name to the commit SHA it points at via GitHub's tags
+/// API, which already returns the dereferenced commit rather than the tag
+/// object (avoiding a second lookup for annotated tags).
+async fn resolve_tag_commit(repo_html_url: &str, tag_name: &str) -> Option<String> {
+    let trimmed = repo_html_url.trim_end_matches('/');
+    let mut segments = trimmed.rsplit('/');
+    let repo = segments.next()?;
+    let owner = segments.next()?;
+
+    let tags: Vec<GithubTag> = reqwest::Client::new()
+        .get(format!("https://api.github.com/repos/{owner}/{repo}/tags"))
+        .header("User-Agent", "solana-verified-programs-api")
+        .send()
+        .await
+        .ok()?
+        .json()
+        .await
+        .ok()?;
+
+    tags.into_iter()
+        .find(|tag| tag.name == tag_name)
+        .map(|tag| tag.commit.sha)
+}
+
+// Route handler for POST /webhook/github which re-triggers verification
+// whenever a configured repository pushes a new commit or cuts a release.
+pub(crate) async fn github_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    body: bytes::Bytes,
+) -> (StatusCode, Json<ApiResponse>) {
+    let signature = match headers
+        .get("X-Hub-Signature-256")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("sha256="))
+    {
+        Some(signature) => signature,
+        None => {
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(
+                    ErrorResponse {
+                        status: Status::Error,
+                        error: "Missing X-Hub-Signature-256 header".to_string(),
+                    }
+                    .into(),
+                ),
+            );
+        }
+    };
+
+    let payload: GithubPushPayload = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(
+                    ErrorResponse {
+                        status: Status::Error,
+                        error: "Unable to parse GitHub webhook payload".to_string(),
+                    }
+                    .into(),
+                ),
+            );
+        }
+    };
+
+    let config = match state
+        .github_webhooks
+        .iter()
+        .find(|config| config.repo == payload.repository.html_url)
+    {
+        Some(config) => config,
+        None => {
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(
+                    ErrorResponse {
+                        status: Status::Error,
+                        error: "No webhook secret configured for this repository".to_string(),
+                    }
+                    .into(),
+                ),
+            );
+        }
+    };
+
+    if !verify_signature(config.secret.as_bytes(), &body, signature) {
+        return (
+            StatusCode::UNAUTHORIZED,
+            Json(
+                ErrorResponse {
+                    status: Status::Error,
+                    error: "Signature verification failed".to_string(),
+                }
+                .into(),
+            ),
+        );
+    }
+
+    let Some(commit_hash) = payload.commit_hash().await else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(
+                ErrorResponse {
+                    status: Status::Error,
+                    error: "Payload did not contain a commit to verify, or the release tag could not be resolved to a commit".to_string(),
+                }
+                .into(),
+            ),
+        );
+    };
+
+    let build_params = SolanaProgramBuildParams {
+        repository: payload.repository.html_url.clone(),
+        commit_hash: Some(commit_hash),
+        program_id: config.program_id.clone(),
+        lib_name: config.lib_name.clone(),
+        ..Default::default()
+    };
+
+    verify_async(State(state), Json(build_params)).await
+}
+
+/// Verifies `signature` (the hex digest from `X-Hub-Signature-256`, minus
+/// the `sha256=` prefix) against an HMAC-SHA256 of `body` keyed by
+/// `secret`. The comparison itself is constant-time; `hex::decode` is not,
+/// but it operates on attacker-controlled hex rather than the secret, so
+/// that isn't a timing side channel worth defending against here.
+fn verify_signature(secret: &[u8], body: &[u8], signature: &str) -> bool {
+    let Ok(expected) = hex::decode(signature) else {
+        return false;
+    };
+
+    let Ok(mut mac) = HmacSha256::new_from_slice(secret) else {
+        return false;
+    };
+    mac.update(body);
+    let computed = mac.finalize().into_bytes();
+
+    computed.ct_eq(&expected[..]).into()
+}
@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use crate::db::DbClient;
+use crate::logs::LogBroadcaster;
+use crate::queue::BuildQueue;
+use crate::routes::webhook::GithubWebhookConfig;
+
+/// Shared axum state for routes that need database access, the live
+/// build-log fan-out, the bounded build queue, and/or the configured
+/// GitHub webhook secrets.
+#[derive(Clone)]
+pub struct AppState {
+    pub db: DbClient,
+    pub logs: LogBroadcaster,
+    pub queue: BuildQueue,
+    pub github_webhooks: Arc<Vec<GithubWebhookConfig>>,
+}